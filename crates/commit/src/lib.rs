@@ -27,7 +27,10 @@
 //! ```
 
 // Re-export everything from commit-info
-pub use commit_info::{Hash, NotFound, Time, commit, dirty};
+pub use commit_info::{
+    AmbiguousPrefix, Ancestry, Describe, Hash, NotFound, PrefixLookupError, Time, bisect, commit,
+    dirty, merge_base, paths_changed, shortest_unique_prefix,
+};
 
 // Re-export everything from commit-pinned
 pub use commit_pinned::{Ignored, Pinned};
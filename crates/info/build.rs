@@ -16,10 +16,22 @@ fn main() {
             println!("cargo:rustc-env=GIT_DIRTY=false");
             println!("cargo:rustc-env=GIT_HISTORY_LEN=0");
 
-            // Write empty history file
+            // Write empty history files
             let out_dir = std::env::var("OUT_DIR").unwrap();
             let history_path = std::path::Path::new(&out_dir).join("history.bin");
             std::fs::write(history_path, &[]).unwrap();
+            let history_sorted_path = std::path::Path::new(&out_dir).join("history_sorted.bin");
+            std::fs::write(history_sorted_path, &[]).unwrap();
+            let names_path = std::path::Path::new(&out_dir).join("names.bin");
+            std::fs::write(names_path, &[]).unwrap();
+            let groups_path = std::path::Path::new(&out_dir).join("path_groups.bin");
+            std::fs::write(groups_path, &[]).unwrap();
+            let paths_path = std::path::Path::new(&out_dir).join("paths.bin");
+            std::fs::write(paths_path, &[]).unwrap();
+            let parents_path = std::path::Path::new(&out_dir).join("parents.bin");
+            std::fs::write(parents_path, &[]).unwrap();
+            let parent_offsets_path = std::path::Path::new(&out_dir).join("parent_offsets.bin");
+            std::fs::write(parent_offsets_path, 0u32.to_le_bytes()).unwrap();
             return;
         }
     };
@@ -27,24 +39,14 @@ fn main() {
     // Get the HEAD commit hash
     let head = repo.head().ok();
 
-    // Collect commit history (SHA-256 hashes of git commit IDs)
-    let history_hashes: Vec<[u8; 32]> = if let Some(head_ref) = &head {
+    // Collect commit history (SHA-256 hashes of git commit IDs), remembering
+    // each commit's raw git Oid alongside its position so tags/branches can
+    // be resolved back to a history index below.
+    let revwalk_oids: Vec<git2::Oid> = if let Some(head_ref) = &head {
         if let Ok(commit) = head_ref.peel_to_commit() {
             let mut revwalk = repo.revwalk().unwrap();
             revwalk.push(commit.id()).unwrap();
-
-            revwalk
-                .filter_map(|oid| oid.ok())
-                .map(|oid| {
-                    // Hash the git commit ID with SHA-256
-                    let mut hasher = Sha256::new();
-                    hasher.update(oid.as_bytes());
-                    let result = hasher.finalize();
-                    let mut hash = [0u8; 32];
-                    hash.copy_from_slice(&result);
-                    hash
-                })
-                .collect()
+            revwalk.filter_map(|oid| oid.ok()).collect()
         } else {
             Vec::new()
         }
@@ -52,6 +54,25 @@ fn main() {
         Vec::new()
     };
 
+    let history_hashes: Vec<[u8; 32]> = revwalk_oids
+        .iter()
+        .map(|oid| {
+            // Hash the git commit ID with SHA-256
+            let mut hasher = Sha256::new();
+            hasher.update(oid.as_bytes());
+            let result = hasher.finalize();
+            let mut hash = [0u8; 32];
+            hash.copy_from_slice(&result);
+            hash
+        })
+        .collect();
+
+    let oid_to_index: std::collections::HashMap<git2::Oid, usize> = revwalk_oids
+        .iter()
+        .enumerate()
+        .map(|(idx, oid)| (*oid, idx))
+        .collect();
+
     // Write history as raw bytes to a file
     let out_dir = std::env::var("OUT_DIR").unwrap();
     let history_path = std::path::Path::new(&out_dir).join("history.bin");
@@ -60,6 +81,172 @@ fn main() {
         file.write_all(hash).unwrap();
     }
 
+    // Write a second artifact: the same hashes sorted lexicographically,
+    // paired with their original (chronological) index, so runtime lookups
+    // can binary search instead of scanning. Each entry is 34 bytes: a
+    // 32-byte hash followed by a little-endian u16 original index.
+    let mut sorted_hashes: Vec<(usize, &[u8; 32])> = history_hashes.iter().enumerate().collect();
+    sorted_hashes.sort_by(|(_, a), (_, b)| a.cmp(b));
+
+    let history_sorted_path = std::path::Path::new(&out_dir).join("history_sorted.bin");
+    let mut sorted_file = std::fs::File::create(&history_sorted_path).unwrap();
+    for (original_index, hash) in &sorted_hashes {
+        sorted_file.write_all(*hash).unwrap();
+        sorted_file
+            .write_all(&(*original_index as u16).to_le_bytes())
+            .unwrap();
+    }
+
+    // Write a table of named refs (tags and branches) resolvable to a
+    // history index, used by `Describe` to render `git describe`-style
+    // labels. Each entry is `name_len:u8 | name_bytes | hash:[u8; 32] |
+    // index:u16 LE`.
+    let mut named_refs: Vec<(String, [u8; 32], usize)> = Vec::new();
+    if let Ok(tag_names) = repo.tag_names(None) {
+        for name in tag_names.iter().flatten() {
+            if let Ok(obj) = repo.revparse_single(name) {
+                if let Ok(commit) = obj.peel_to_commit() {
+                    if let Some(&index) = oid_to_index.get(&commit.id()) {
+                        named_refs.push((name.to_string(), history_hashes[index], index));
+                    }
+                }
+            }
+        }
+    }
+    if let Ok(branches) = repo.branches(Some(git2::BranchType::Local)) {
+        for branch in branches.filter_map(|b| b.ok()) {
+            let (branch, _) = branch;
+            if let (Some(name), Some(target)) = (
+                branch.name().ok().flatten(),
+                branch.get().peel_to_commit().ok(),
+            ) {
+                if let Some(&index) = oid_to_index.get(&target.id()) {
+                    named_refs.push((name.to_string(), history_hashes[index], index));
+                }
+            }
+        }
+    }
+
+    let names_path = std::path::Path::new(&out_dir).join("names.bin");
+    let mut names_file = std::fs::File::create(&names_path).unwrap();
+    for (name, hash, index) in &named_refs {
+        let name_bytes = name.as_bytes();
+        names_file.write_all(&[name_bytes.len() as u8]).unwrap();
+        names_file.write_all(name_bytes).unwrap();
+        names_file.write_all(hash).unwrap();
+        names_file
+            .write_all(&(*index as u16).to_le_bytes())
+            .unwrap();
+    }
+
+    // Write path-scoped change tracking artifacts: for each tracked group of
+    // path globs (configured via the `COMMIT_TRACKED_PATHS` env var as
+    // `group=glob1:glob2,group2=glob3`), record a bitset of which groups
+    // each commit touched relative to its first parent. This lets callers
+    // ask "did subsystem X change between these two pinned builds?" without
+    // the all-or-nothing `dirty` flag.
+    let groups: Vec<(String, Vec<String>)> = std::env::var("COMMIT_TRACKED_PATHS")
+        .unwrap_or_default()
+        .split(',')
+        .filter(|s| !s.is_empty())
+        .filter_map(|entry| {
+            let (name, globs) = entry.split_once('=')?;
+            Some((
+                name.to_string(),
+                globs.split(':').map(|g| g.to_string()).collect(),
+            ))
+        })
+        .collect();
+
+    assert!(
+        groups.len() <= 32,
+        "COMMIT_TRACKED_PATHS defines {} groups, but at most 32 are supported \
+         (each commit's touched groups are packed into a single u32 bitset)",
+        groups.len()
+    );
+
+    let groups_path = std::path::Path::new(&out_dir).join("path_groups.bin");
+    let mut groups_file = std::fs::File::create(&groups_path).unwrap();
+    for (name, _) in &groups {
+        let name_bytes = name.as_bytes();
+        groups_file.write_all(&[name_bytes.len() as u8]).unwrap();
+        groups_file.write_all(name_bytes).unwrap();
+    }
+
+    let paths_path = std::path::Path::new(&out_dir).join("paths.bin");
+    let mut paths_file = std::fs::File::create(&paths_path).unwrap();
+    if !groups.is_empty() {
+        for oid in &revwalk_oids {
+            let commit = repo.find_commit(*oid).unwrap();
+            let tree = commit.tree().ok();
+            let parent_tree = commit.parents().next().and_then(|p| p.tree().ok());
+
+            let mut changed_paths: Vec<String> = Vec::new();
+            if let Ok(diff) =
+                repo.diff_tree_to_tree(parent_tree.as_ref(), tree.as_ref(), None)
+            {
+                let _ = diff.foreach(
+                    &mut |delta, _| {
+                        if let Some(path) = delta.new_file().path().or_else(|| delta.old_file().path()) {
+                            changed_paths.push(path.to_string_lossy().to_string());
+                        }
+                        true
+                    },
+                    None,
+                    None,
+                    None,
+                );
+            }
+
+            let mut bitset: u32 = 0;
+            for (bit, (_, globs)) in groups.iter().enumerate() {
+                let touched = changed_paths
+                    .iter()
+                    .any(|p| globs.iter().any(|g| glob_match(g, p)));
+                if touched {
+                    bitset |= 1 << bit;
+                }
+            }
+            paths_file.write_all(&bitset.to_le_bytes()).unwrap();
+        }
+    }
+
+    // Write the parent DAG: for each commit index, the indices of its
+    // parent commit(s), so `Time` can answer ancestry queries instead of
+    // only comparing positions in the flat chronological list. Parents
+    // outside the walked history (e.g. a shallow clone's boundary) are
+    // simply omitted.
+    //
+    // `parents.bin` holds variable-length records `count:u8 | (index:u16
+    // LE)*count`, one per history index in order. `parent_offsets.bin`
+    // holds `HISTORY_LEN + 1` little-endian `u32` byte offsets into
+    // `parents.bin` (a prefix-sum table), so a given index's record can be
+    // sliced out directly.
+    let mut parents_data: Vec<u8> = Vec::new();
+    let mut parent_offsets: Vec<u32> = Vec::with_capacity(revwalk_oids.len() + 1);
+    for oid in &revwalk_oids {
+        parent_offsets.push(parents_data.len() as u32);
+        let commit = repo.find_commit(*oid).unwrap();
+        let parent_indices: Vec<u16> = commit
+            .parent_ids()
+            .filter_map(|pid| oid_to_index.get(&pid).map(|&idx| idx as u16))
+            .collect();
+        parents_data.push(parent_indices.len() as u8);
+        for idx in parent_indices {
+            parents_data.extend_from_slice(&idx.to_le_bytes());
+        }
+    }
+    parent_offsets.push(parents_data.len() as u32);
+
+    let parents_path = std::path::Path::new(&out_dir).join("parents.bin");
+    std::fs::write(&parents_path, &parents_data).unwrap();
+
+    let parent_offsets_path = std::path::Path::new(&out_dir).join("parent_offsets.bin");
+    let mut parent_offsets_file = std::fs::File::create(&parent_offsets_path).unwrap();
+    for offset in &parent_offsets {
+        parent_offsets_file.write_all(&offset.to_le_bytes()).unwrap();
+    }
+
     // Check if the working directory is dirty
     let mut status_options = git2::StatusOptions::new();
     status_options.include_untracked(true);
@@ -84,4 +271,30 @@ fn main() {
     // Rerun if git state changes
     println!("cargo:rerun-if-changed=.git/HEAD");
     println!("cargo:rerun-if-changed=.git/index");
+    println!("cargo:rerun-if-changed=.git/refs");
+    println!("cargo:rerun-if-changed=.git/packed-refs");
+    println!("cargo:rerun-if-env-changed=COMMIT_TRACKED_PATHS");
+}
+
+/// Minimal glob matcher supporting `*` (any run of characters) and `**`
+/// (same as `*`, since paths are matched as whole strings rather than
+/// segment-by-segment). Good enough for `build.rs`-time path filtering.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    glob_match_inner(&pattern, &text)
+}
+
+fn glob_match_inner(pattern: &[char], text: &[char]) -> bool {
+    match pattern.first() {
+        None => text.is_empty(),
+        Some('*') => {
+            let rest = &pattern[1..];
+            glob_match_inner(rest, text)
+                || (!text.is_empty() && glob_match_inner(pattern, &text[1..]))
+        }
+        Some(c) => {
+            matches!(text.first(), Some(t) if t == c) && glob_match_inner(&pattern[1..], &text[1..])
+        }
+    }
 }
@@ -3,6 +3,7 @@
 
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
+use std::collections::{HashSet, VecDeque};
 
 /// A 32-byte SHA-256 hash representing a commit
 pub type Hash = [u8; 32];
@@ -28,6 +29,45 @@ impl std::fmt::Display for NotFound {
 
 impl std::error::Error for NotFound {}
 
+/// A hex prefix matched more than one commit in the embedded history.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AmbiguousPrefix {
+    /// The full hashes of every commit the prefix matched.
+    pub matches: Vec<Hash>,
+}
+
+impl std::fmt::Display for AmbiguousPrefix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ambiguous commit prefix: {} commits matched",
+            self.matches.len()
+        )
+    }
+}
+
+impl std::error::Error for AmbiguousPrefix {}
+
+/// Error resolving a hex hash prefix to a commit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PrefixLookupError {
+    /// No commit in the history matched the prefix.
+    NotFound,
+    /// More than one commit matched the prefix.
+    Ambiguous(AmbiguousPrefix),
+}
+
+impl std::fmt::Display for PrefixLookupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PrefixLookupError::NotFound => write!(f, "commit not found in history"),
+            PrefixLookupError::Ambiguous(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for PrefixLookupError {}
+
 /// The current commit hash as a hex string
 pub const COMMIT_STRING: &str = env!("GIT_COMMIT");
 
@@ -37,6 +77,42 @@ const HISTORY_LEN: usize = const_str::parse!(env!("GIT_HISTORY_LEN"), usize);
 // Include the raw history bytes at compile time
 const HISTORY_BYTES: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/history.bin"));
 
+// Include the sorted-by-hash lookup table: HISTORY_LEN entries of 34 bytes
+// each (32-byte hash + u16 LE original index), sorted lexicographically by
+// hash so `get_index`/`get_index_const` can binary search instead of scan.
+const HISTORY_SORTED_BYTES: &[u8] =
+    include_bytes!(concat!(env!("OUT_DIR"), "/history_sorted.bin"));
+const SORTED_ENTRY_LEN: usize = 34;
+
+// Include the named-refs table: variable-length entries of
+// `name_len:u8 | name_bytes | hash:[u8; 32] | index:u16 LE`, one per tag or
+// local branch known at build time.
+const NAMES_BYTES: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/names.bin"));
+
+// Include the path-group change tracking tables. `PATH_GROUPS_BYTES` holds
+// the tracked group names (`name_len:u8 | name_bytes`, one per group, in bit
+// order); `PATHS_BYTES` holds one little-endian `u32` bitset per history
+// index recording which groups that commit touched relative to its parent
+// (and is empty when no groups are configured).
+// Limited to 32 `COMMIT_TRACKED_PATHS` groups, since each commit's groups
+// are packed into a single `u32` bitset; `build.rs` refuses to build past
+// that limit.
+const PATH_GROUPS_BYTES: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/path_groups.bin"));
+const PATHS_BYTES: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/paths.bin"));
+
+// Include the parent DAG. `PARENTS_BYTES` holds variable-length records
+// (`count:u8 | (index:u16 LE)*count`), one per history index.
+// `PARENT_OFFSETS_BYTES` holds `HISTORY_LEN + 1` little-endian `u32` byte
+// offsets into `PARENTS_BYTES`, so a given index's record can be sliced out
+// directly without scanning.
+const PARENTS_BYTES: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/parents.bin"));
+const PARENT_OFFSETS_BYTES: &[u8] =
+    include_bytes!(concat!(env!("OUT_DIR"), "/parent_offsets.bin"));
+
+/// The default number of hex characters used to abbreviate a commit hash in
+/// [`Describe`] output, mirroring git's default `--abbrev` length.
+pub const DEFAULT_ABBREV_LEN: usize = 7;
+
 /// The current commit hash as a byte array
 pub const COMMIT: Hash = parse_commit_hash(COMMIT_STRING);
 
@@ -72,6 +148,272 @@ impl Time {
     {
         range.contains(self)
     }
+
+    /// Check whether this commit touched the named path group, relative to
+    /// its parent. Groups are configured at build time via
+    /// `COMMIT_TRACKED_PATHS`; an unknown group name always returns `false`.
+    pub fn changed(&self, group: &str) -> bool {
+        match group_bit(group) {
+            Some(bit) => path_bitset(self.0) & (1 << bit) != 0,
+            None => false,
+        }
+    }
+
+    /// Resolve an abbreviated hex hash prefix (odd or even length) against
+    /// the embedded history, binary searching the sorted hash table for the
+    /// matching run of entries. Returns [`PrefixLookupError::NotFound`] if
+    /// nothing matches, or [`PrefixLookupError::Ambiguous`] carrying every
+    /// matching full hash if more than one commit shares the prefix.
+    pub fn from_hash_prefix(prefix: &str) -> Result<Self, PrefixLookupError> {
+        let nibbles = parse_hex_nibbles(prefix).ok_or(PrefixLookupError::NotFound)?;
+        if nibbles.is_empty() {
+            return Err(PrefixLookupError::NotFound);
+        }
+
+        let matches = matching_sorted_entries(&nibbles);
+        match matches.len() {
+            0 => Err(PrefixLookupError::NotFound),
+            1 => Ok(Time(matches[0].1)),
+            _ => Err(PrefixLookupError::Ambiguous(AmbiguousPrefix {
+                matches: matches.into_iter().map(|(hash, _)| hash).collect(),
+            })),
+        }
+    }
+
+    /// Iterate over this commit's parent(s), in the order git recorded
+    /// them (first parent first for a merge).
+    pub fn parents(&self) -> impl Iterator<Item = Time> {
+        parent_indices(self.0).into_iter().map(Time)
+    }
+
+    /// Check whether `self` is an ancestor of `other` (or the same
+    /// commit). Walks `other`'s ancestry via BFS, pruning any branch whose
+    /// index has already passed `self`'s index, since parents always have
+    /// a larger (older) index than their child in this history.
+    pub fn is_ancestor_of(&self, other: &Time) -> bool {
+        ancestry_bfs(other.0, self.0).0
+    }
+
+    /// Same as [`Self::is_ancestor_of`], but also reports how many commits
+    /// the BFS visited, so callers can gauge traversal cost.
+    pub fn ancestry(&self, other: &Time) -> Ancestry {
+        let (is_ancestor, commits_seen) = ancestry_bfs(other.0, self.0);
+        Ancestry {
+            is_ancestor,
+            commits_seen,
+        }
+    }
+}
+
+/// The result of an ancestry check, including how many commits the search
+/// visited ([`Time::ancestry`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ancestry {
+    pub is_ancestor: bool,
+    pub commits_seen: usize,
+}
+
+/// Find the merge base (nearest common ancestor) of `a` and `b`, or `None`
+/// if they share no ancestor in the embedded history.
+pub fn merge_base(a: Time, b: Time) -> Option<Time> {
+    let ancestors_of_a = collect_ancestors(a.0);
+
+    let mut visited = HashSet::new();
+    let mut queue = VecDeque::new();
+    visited.insert(b.0);
+    queue.push_back(b.0);
+
+    let mut best: Option<u16> = None;
+    while let Some(idx) = queue.pop_front() {
+        if ancestors_of_a.contains(&idx) {
+            best = Some(match best {
+                Some(current) if current <= idx => current,
+                _ => idx,
+            });
+        }
+        for parent in parent_indices(idx) {
+            if visited.insert(parent) {
+                queue.push_back(parent);
+            }
+        }
+    }
+    best.map(Time)
+}
+
+/// BFS from `descendant` toward `ancestor` over the parent DAG, pruning any
+/// branch whose index has passed `ancestor` (parents only get older).
+/// Returns whether `ancestor` was reached and how many commits were
+/// visited.
+fn ancestry_bfs(descendant: u16, ancestor: u16) -> (bool, usize) {
+    let mut visited = HashSet::new();
+    let mut queue = VecDeque::new();
+    visited.insert(descendant);
+    queue.push_back(descendant);
+
+    let mut seen = 0usize;
+    while let Some(idx) = queue.pop_front() {
+        seen += 1;
+        if idx == ancestor {
+            return (true, seen);
+        }
+        if idx > ancestor {
+            continue;
+        }
+        for parent in parent_indices(idx) {
+            if visited.insert(parent) {
+                queue.push_back(parent);
+            }
+        }
+    }
+    (false, seen)
+}
+
+/// Collect every ancestor of `index` (inclusive) via unbounded BFS over the
+/// parent DAG.
+fn collect_ancestors(index: u16) -> HashSet<u16> {
+    let mut visited = HashSet::new();
+    let mut queue = VecDeque::new();
+    visited.insert(index);
+    queue.push_back(index);
+    while let Some(idx) = queue.pop_front() {
+        for parent in parent_indices(idx) {
+            if visited.insert(parent) {
+                queue.push_back(parent);
+            }
+        }
+    }
+    visited
+}
+
+/// Read the parent indices of `index` out of the offset-indexed parent
+/// table.
+fn parent_indices(index: u16) -> Vec<u16> {
+    let offset_at = |i: usize| -> u32 {
+        let o = i * 4;
+        u32::from_le_bytes(PARENT_OFFSETS_BYTES[o..o + 4].try_into().unwrap())
+    };
+
+    let start = offset_at(index as usize) as usize;
+    let count = PARENTS_BYTES[start] as usize;
+    let mut result = Vec::with_capacity(count);
+    let mut pos = start + 1;
+    for _ in 0..count {
+        result.push(u16::from_le_bytes([PARENTS_BYTES[pos], PARENTS_BYTES[pos + 1]]));
+        pos += 2;
+    }
+    result
+}
+
+/// Compute the minimal number of hex digits needed to unambiguously
+/// identify `hash` within the embedded history, mirroring git's
+/// `--abbrev` behavior. Falls back to the full 64-character hash if no
+/// prefix is unique (e.g. a duplicate hash, an empty history, or a `hash`
+/// that is not in the history).
+pub fn shortest_unique_prefix(hash: &Hash) -> String {
+    let full_hex: String = hash.iter().map(|b| format!("{:02x}", b)).collect();
+    for len in 1..=full_hex.len() {
+        let nibbles = parse_hex_nibbles(&full_hex[..len]).unwrap_or_default();
+        let matches = matching_sorted_entries(&nibbles);
+        if matches.len() == 1 && matches[0].0 == *hash {
+            return full_hex[..len].to_string();
+        }
+    }
+    full_hex
+}
+
+fn parse_hex_nibbles(prefix: &str) -> Option<Vec<u8>> {
+    if prefix.is_empty() || prefix.len() > 64 {
+        return None;
+    }
+    prefix
+        .chars()
+        .map(|c| c.to_digit(16).map(|d| d as u8))
+        .collect()
+}
+
+/// Ordering of `hash`'s prefix nibbles against `nibbles`, treating a hash
+/// whose prefix matches as `Equal` regardless of the remaining bytes.
+fn prefix_cmp(hash: &Hash, nibbles: &[u8]) -> Ordering {
+    for (i, &nibble) in nibbles.iter().enumerate() {
+        let byte = hash[i / 2];
+        let actual = if i % 2 == 0 { byte >> 4 } else { byte & 0x0f };
+        match actual.cmp(&nibble) {
+            Ordering::Equal => continue,
+            other => return other,
+        }
+    }
+    Ordering::Equal
+}
+
+/// Binary search `HISTORY_SORTED_BYTES` for the contiguous run of entries
+/// whose hash starts with `nibbles`, returning each match's full hash and
+/// chronological index.
+fn matching_sorted_entries(nibbles: &[u8]) -> Vec<(Hash, u16)> {
+    let entry_hash = |i: usize| -> Hash {
+        let offset = i * SORTED_ENTRY_LEN;
+        HISTORY_SORTED_BYTES[offset..offset + 32].try_into().unwrap()
+    };
+    let entry_index = |i: usize| -> u16 {
+        let offset = i * SORTED_ENTRY_LEN;
+        u16::from_le_bytes([
+            HISTORY_SORTED_BYTES[offset + 32],
+            HISTORY_SORTED_BYTES[offset + 33],
+        ])
+    };
+
+    let mut lo = 0usize;
+    let mut hi = HISTORY_LEN;
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if prefix_cmp(&entry_hash(mid), nibbles) == Ordering::Less {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+
+    let mut matches = Vec::new();
+    let mut i = lo;
+    while i < HISTORY_LEN && prefix_cmp(&entry_hash(i), nibbles) == Ordering::Equal {
+        matches.push((entry_hash(i), entry_index(i)));
+        i += 1;
+    }
+    matches
+}
+
+/// Check whether `group` changed anywhere between two pinned builds,
+/// inclusive of both endpoints. ORs the per-commit bitsets across the index
+/// range between `a` and `b` (order doesn't matter).
+pub fn paths_changed(a: Time, b: Time, group: &str) -> bool {
+    let Some(bit) = group_bit(group) else {
+        return false;
+    };
+    let (lo, hi) = if a.0 <= b.0 { (a.0, b.0) } else { (b.0, a.0) };
+    (lo..=hi).any(|idx| path_bitset(idx) & (1 << bit) != 0)
+}
+
+fn path_bitset(index: u16) -> u32 {
+    let offset = index as usize * 4;
+    u32::from_le_bytes(PATHS_BYTES[offset..offset + 4].try_into().unwrap())
+}
+
+/// Resolve a tracked path-group name to its bit position in the bitset,
+/// scanning `PATH_GROUPS_BYTES` in the order `build.rs` wrote them.
+fn group_bit(group: &str) -> Option<u8> {
+    let mut offset = 0;
+    let mut bit = 0u8;
+    while offset < PATH_GROUPS_BYTES.len() {
+        let name_len = PATH_GROUPS_BYTES[offset] as usize;
+        let name_start = offset + 1;
+        let name_end = name_start + name_len;
+        let name = std::str::from_utf8(&PATH_GROUPS_BYTES[name_start..name_end]).unwrap_or("");
+        if name == group {
+            return Some(bit);
+        }
+        offset = name_end;
+        bit += 1;
+    }
+    None
 }
 
 impl PartialOrd for Time {
@@ -87,6 +429,139 @@ impl Ord for Time {
     }
 }
 
+/// A `git describe`-style label for a pinned commit: the nearest named ref,
+/// how many commits past it, and an abbreviated hash, e.g.
+/// `v1.2.3-4-gabc1234` or `v1.2.3-4-gabc1234-dirty`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Describe {
+    name: Option<String>,
+    depth: u16,
+    abbrev: String,
+    dirty: bool,
+}
+
+impl Describe {
+    /// Render a label for `hash`, abbreviating to [`DEFAULT_ABBREV_LEN`] hex
+    /// characters.
+    pub fn for_hash(hash: &Hash) -> Result<Self, NotFound> {
+        Self::for_hash_with_abbrev(hash, DEFAULT_ABBREV_LEN)
+    }
+
+    /// Render a label for `hash`, abbreviating to `abbrev_len` hex
+    /// characters.
+    pub fn for_hash_with_abbrev(hash: &Hash, abbrev_len: usize) -> Result<Self, NotFound> {
+        let time = Time::from_hash(hash)?;
+        let (name, depth) = nearest_named_ref(time.index());
+        let abbrev = hex_prefix(hash, abbrev_len);
+        Ok(Self {
+            name,
+            depth,
+            abbrev,
+            dirty: false,
+        })
+    }
+
+    /// Mark this label as describing a dirty working directory, appending
+    /// `-dirty` when displayed.
+    pub fn with_dirty(mut self, dirty: bool) -> Self {
+        self.dirty = dirty;
+        self
+    }
+}
+
+impl std::fmt::Display for Describe {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "{name}-{}-g{}", self.depth, self.abbrev)?,
+            None => write!(f, "g{}", self.abbrev)?,
+        }
+        if self.dirty {
+            write!(f, "-dirty")?;
+        }
+        Ok(())
+    }
+}
+
+/// Find the nearest named ref at or after `index` (i.e. at the same commit
+/// or toward older commits), returning its name and the depth from `index`.
+fn nearest_named_ref(index: u16) -> (Option<String>, u16) {
+    let mut best: Option<(&str, u16)> = None;
+    let mut offset = 0;
+    while offset < NAMES_BYTES.len() {
+        let name_len = NAMES_BYTES[offset] as usize;
+        let name_start = offset + 1;
+        let name_end = name_start + name_len;
+        let index_start = name_end + 32;
+        let entry_index = u16::from_le_bytes([NAMES_BYTES[index_start], NAMES_BYTES[index_start + 1]]);
+
+        if entry_index >= index {
+            let name = std::str::from_utf8(&NAMES_BYTES[name_start..name_end]).unwrap_or("");
+            let better = match best {
+                Some((_, best_index)) => entry_index < best_index,
+                None => true,
+            };
+            if better {
+                best = Some((name, entry_index));
+            }
+        }
+
+        offset = index_start + 2;
+    }
+    match best {
+        Some((name, entry_index)) => (Some(name.to_string()), entry_index - index),
+        None => (None, 0),
+    }
+}
+
+fn hex_prefix(hash: &Hash, len: usize) -> String {
+    hash.iter()
+        .take(len.div_ceil(2))
+        .map(|b| format!("{:02x}", b))
+        .collect::<String>()
+        .chars()
+        .take(len)
+        .collect()
+}
+
+/// Binary-search the embedded history for the boundary where `pred` flips,
+/// mirroring `git bisect`.
+///
+/// History is ordered newest-to-oldest across indices `0..HISTORY_LEN`.
+/// `pred` must be monotonic over that range: it should return
+/// [`Ordering::Less`] for every index where "the boundary is newer" holds
+/// and [`Ordering::Greater`] where "the boundary is older" holds (or
+/// [`Ordering::Equal`] for an exact match), with at most one flip between
+/// the `Less` and `Greater` regions. `bisect` compares each midpoint's
+/// answer against the newest commit's answer rather than hardcoding which
+/// `Ordering` variant narrows which bound, so it converges on the first
+/// index whose answer differs from index 0's — the older side of the
+/// flip. Returns `None` if the history is empty.
+pub fn bisect(pred: impl Fn(Time) -> Ordering) -> Option<Time> {
+    if HISTORY_LEN == 0 {
+        return None;
+    }
+    let mut lo: u16 = 0;
+    let mut hi: u16 = (HISTORY_LEN - 1) as u16;
+    let lo_answer = pred(Time(lo));
+    if lo_answer == Ordering::Equal {
+        return Some(Time(lo));
+    }
+
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        let mid_answer = pred(Time(mid));
+        if mid_answer == Ordering::Equal {
+            return Some(Time(mid));
+        }
+        if mid_answer == lo_answer {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    Some(Time(lo))
+}
+
 /// Check if the working directory was dirty at build time
 pub fn dirty() -> bool {
     DIRTY.parse().unwrap_or(false)
@@ -99,32 +574,67 @@ fn history() -> &'static [[u8; 32]] {
     unsafe { std::slice::from_raw_parts(HISTORY_BYTES.as_ptr() as *const [u8; 32], HISTORY_LEN) }
 }
 
-/// Get the index of a commit in the history (private, for internal use)
+/// Get the index of a commit in the history (private, for internal use).
+///
+/// Binary searches `HISTORY_SORTED_BYTES`, which is sorted lexicographically
+/// by hash, rather than scanning `HISTORY_BYTES` in chronological order.
 fn get_index(hash: &Hash) -> Option<u16> {
-    history()
-        .iter()
-        .position(|h| h == hash)
-        .and_then(|idx| u16::try_from(idx).ok())
+    let mut lo = 0usize;
+    let mut hi = HISTORY_LEN;
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        let offset = mid * SORTED_ENTRY_LEN;
+        let entry_hash = &HISTORY_SORTED_BYTES[offset..offset + 32];
+        match entry_hash.cmp(hash.as_slice()) {
+            Ordering::Less => lo = mid + 1,
+            Ordering::Greater => hi = mid,
+            Ordering::Equal => {
+                let idx_bytes = [
+                    HISTORY_SORTED_BYTES[offset + 32],
+                    HISTORY_SORTED_BYTES[offset + 33],
+                ];
+                return Some(u16::from_le_bytes(idx_bytes));
+            }
+        }
+    }
+    None
 }
 
-/// Const version of get_index for compile-time usage
+/// Const version of get_index for compile-time usage.
+///
+/// Same binary search as [`get_index`], written as a `while` loop since
+/// `const fn` cannot use iterators or slice comparison operators.
 const fn get_index_const(hash: &Hash) -> Option<u16> {
-    let mut i = 0;
-    while i < HISTORY_LEN {
-        let offset = i * 32;
-        let mut matches = true;
+    let mut lo = 0usize;
+    let mut hi = HISTORY_LEN;
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        let offset = mid * SORTED_ENTRY_LEN;
+
         let mut j = 0;
+        let mut ordering = Ordering::Equal;
         while j < 32 {
-            if HISTORY_BYTES[offset + j] != hash[j] {
-                matches = false;
+            let a = HISTORY_SORTED_BYTES[offset + j];
+            let b = hash[j];
+            if a < b {
+                ordering = Ordering::Less;
+                break;
+            } else if a > b {
+                ordering = Ordering::Greater;
                 break;
             }
             j += 1;
         }
-        if matches {
-            return Some(i as u16);
+
+        match ordering {
+            Ordering::Less => lo = mid + 1,
+            Ordering::Greater => hi = mid,
+            Ordering::Equal => {
+                let idx = (HISTORY_SORTED_BYTES[offset + 32] as u16)
+                    | ((HISTORY_SORTED_BYTES[offset + 33] as u16) << 8);
+                return Some(idx);
+            }
         }
-        i += 1;
     }
     None
 }
@@ -188,6 +698,78 @@ mod tests {
         assert_eq!(time.index(), 0);
     }
 
+    #[test]
+    fn test_get_index_resolves_interior_entry() {
+        // Exercise the sorted-table binary search on an entry other than
+        // index 0, which is otherwise never touched by the other tests.
+        if HISTORY_LEN > 1 {
+            let hash = history()[1];
+            let time = Time::from_hash(&hash).unwrap();
+            assert_eq!(time.index(), 1);
+
+            let last = history()[HISTORY_LEN - 1];
+            let time = Time::from_hash(&last).unwrap();
+            assert_eq!(time.index(), (HISTORY_LEN - 1) as u16);
+        }
+    }
+
+    #[test]
+    fn test_get_index_not_found() {
+        let bogus = [0xabu8; 32];
+        assert!(history().iter().all(|h| *h != bogus));
+        assert_eq!(Time::from_hash(&bogus), Err(NotFound { hash: bogus }));
+    }
+
+    #[test]
+    fn test_from_hash_prefix_full_hash() {
+        let hex: String = COMMIT.iter().map(|b| format!("{:02x}", b)).collect();
+        let time = Time::from_hash_prefix(&hex).unwrap();
+        assert_eq!(time.index(), 0);
+    }
+
+    #[test]
+    fn test_from_hash_prefix_not_found() {
+        let result = Time::from_hash_prefix("ffffffffffffffffffffffffffffffff");
+        assert_eq!(result, Err(PrefixLookupError::NotFound));
+    }
+
+    #[test]
+    fn test_shortest_unique_prefix_resolves_back() {
+        let prefix = shortest_unique_prefix(&COMMIT);
+        assert!(prefix.len() <= 64);
+        let time = Time::from_hash_prefix(&prefix).unwrap();
+        assert_eq!(time.index(), 0);
+    }
+
+    #[test]
+    fn test_is_ancestor_of_self() {
+        let time = Time::from_hash(&COMMIT).unwrap();
+        assert!(time.is_ancestor_of(&time));
+    }
+
+    #[test]
+    fn test_ancestry_reports_commits_seen() {
+        let time = Time::from_hash(&COMMIT).unwrap();
+        let ancestry = time.ancestry(&time);
+        assert!(ancestry.is_ancestor);
+        assert!(ancestry.commits_seen >= 1);
+    }
+
+    #[test]
+    fn test_merge_base_of_same_commit_is_itself() {
+        let time = Time::from_hash(&COMMIT).unwrap();
+        assert_eq!(merge_base(time, time), Some(time));
+    }
+
+    #[test]
+    fn test_parents_are_strictly_older() {
+        let time = Time::from_hash(&COMMIT).unwrap();
+        // Every parent reported must be strictly older than the child.
+        for parent in time.parents() {
+            assert!(parent.index() > time.index());
+        }
+    }
+
     #[test]
     fn test_commit_time_ranges() {
         // Remember: lower index = later in time (reversed ordering)
@@ -212,6 +794,56 @@ mod tests {
         assert_eq!(const_time.index(), runtime_time.index());
     }
 
+    #[test]
+    fn test_describe_current_commit() {
+        let describe = Describe::for_hash(&COMMIT).unwrap();
+        // No guarantee the test repo has tags, but the abbreviated hash and
+        // Display impl should always work.
+        let rendered = describe.to_string();
+        assert!(rendered.contains('g'));
+    }
+
+    #[test]
+    fn test_describe_dirty_suffix() {
+        let describe = Describe::for_hash(&COMMIT).unwrap().with_dirty(true);
+        assert!(describe.to_string().ends_with("-dirty"));
+    }
+
+    #[test]
+    fn test_bisect_finds_flip() {
+        // Pretend the flip sits between indices 2 and 3: indices < 3 are
+        // newer commits (boundary older, `Greater`) and indices >= 3 are
+        // older ones (boundary newer, `Less`); bisect should land on index 3.
+        const BOUNDARY: u16 = 3;
+        let result = bisect(|time| {
+            if time.index() < BOUNDARY {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            }
+        });
+        if HISTORY_LEN as u16 > BOUNDARY {
+            assert_eq!(result.unwrap().index(), BOUNDARY);
+        }
+    }
+
+    #[test]
+    fn test_changed_unknown_group_is_false() {
+        assert!(!Time(0).changed("nonexistent-group"));
+    }
+
+    #[test]
+    fn test_paths_changed_unknown_group_is_false() {
+        assert!(!paths_changed(Time(0), Time(1), "nonexistent-group"));
+    }
+
+    #[test]
+    fn test_bisect_empty_history_is_none() {
+        if HISTORY_LEN == 0 {
+            assert!(bisect(|_| Ordering::Equal).is_none());
+        }
+    }
+
     #[test]
     fn test_match_with_const_ranges() {
         // Test that const indices work in match patterns
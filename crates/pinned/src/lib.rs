@@ -1,8 +1,13 @@
-use commit_info::{Hash, NotFound, Time, commit, dirty};
+use commit_info::{Describe, Hash, NotFound, Time, commit, dirty, paths_changed};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// Placeholder type that ignores content during deserialization.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 pub struct Ignored;
 
 impl Serialize for Ignored {
@@ -26,7 +31,17 @@ impl<'de> Deserialize<'de> for Ignored {
 
 /// A wrapper that pins content to a specific git commit.
 /// Tracks the commit hash, dirty status, and allows temporal ordering via Time.
+///
+/// With the `rkyv` feature enabled, `Pinned` also derives `rkyv::Archive`,
+/// letting a serialized blob be validated and read in place (e.g. via an
+/// `mmap`) without deserializing `content`. See [`ArchivedPinned::commit`]
+/// for gating a migration decision before materializing the payload.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 pub struct Pinned<T = Ignored> {
     /// The git commit hash (SHA-256)
     commit: Hash,
@@ -66,6 +81,24 @@ impl<T> Pinned<T> {
         self.dirty
     }
 
+    /// Get a `git describe`-style label for the pinned commit, e.g.
+    /// `v1.2.3-4-gabc1234`, with a `-dirty` suffix when [`Self::dirty`] is
+    /// true.
+    pub fn describe(&self) -> Result<Describe, NotFound> {
+        Describe::for_hash(&self.commit).map(|d| d.with_dirty(self.dirty))
+    }
+
+    /// Check whether the tracked path group `group` changed anywhere
+    /// between `other` and this pinned commit. Returns `false` (rather than
+    /// erroring) if this commit isn't in the embedded history, since that
+    /// just means no tracked change can be attributed to it.
+    pub fn paths_changed_since(&self, other: &Time, group: &str) -> bool {
+        match self.commit_time() {
+            Ok(time) => paths_changed(time, *other, group),
+            Err(_) => false,
+        }
+    }
+
     /// Get a reference to the content.
     pub fn content(&self) -> &T {
         &self.content
@@ -111,6 +144,30 @@ where
     }
 }
 
+/// Accessors on the zero-copy archived form of [`Pinned`], available when
+/// the `rkyv` feature is enabled. These read directly out of the archived
+/// bytes (e.g. an `mmap`), so the commit can be checked before `content` is
+/// ever deserialized.
+#[cfg(feature = "rkyv")]
+impl<T: rkyv::Archive> ArchivedPinned<T> {
+    /// Get the archived commit hash without deserializing `content`.
+    pub fn commit(&self) -> &Hash {
+        &self.commit
+    }
+
+    /// Resolve the archived commit hash to a [`Time`] for ordering/range
+    /// checks, without deserializing `content`.
+    pub fn commit_time(&self) -> Result<Time, NotFound> {
+        Time::from_hash(&self.commit)
+    }
+
+    /// Whether the working directory was dirty at build time, read directly
+    /// from the archive.
+    pub fn dirty(&self) -> bool {
+        self.dirty
+    }
+}
+
 fn hex_encode(bytes: &[u8; 32]) -> String {
     bytes.iter().map(|b| format!("{:02x}", b)).collect()
 }
@@ -163,4 +220,28 @@ mod tests {
         let time = pinned.commit_time().unwrap();
         println!("Time: {:?}", time);
     }
+
+    #[test]
+    fn test_describe() {
+        let pinned = Pinned::new(42);
+        let describe = pinned.describe().unwrap();
+        assert_eq!(describe.to_string().ends_with("-dirty"), pinned.dirty());
+    }
+
+    #[test]
+    fn test_paths_changed_since_unknown_group() {
+        let pinned = Pinned::new(42);
+        let other = pinned.commit_time().unwrap();
+        assert!(!pinned.paths_changed_since(&other, "nonexistent-group"));
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn test_rkyv_roundtrip() {
+        let pinned = Pinned::new(42u64);
+        let bytes = rkyv::to_bytes::<_, 256>(&pinned).unwrap();
+        let archived = rkyv::check_archived_root::<Pinned<u64>>(&bytes).unwrap();
+        assert_eq!(archived.commit(), pinned.commit());
+        assert_eq!(archived.dirty(), pinned.dirty());
+    }
 }